@@ -0,0 +1,286 @@
+use crate::{Flag, FlagType};
+use std::io::{self, Write};
+
+/// Shells `generate_completions` knows how to emit a script for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Elvish,
+    PowerShell,
+}
+
+/// Emit a completion script for `shell` to `out`, offering each of `commands` as the first
+/// positional completion and, once a subcommand is chosen, its `flags` as long-option
+/// completions.
+///
+/// `commands` pairs a subcommand name with the `Flag`s it declares; the caller gathers these.
+/// The script is written to `out` as-is, ready to be saved, e.g. as `_mycli` for zsh.
+pub fn generate_completions(
+    shell: Shell,
+    bin_name: &str,
+    commands: &[(&str, &[Flag])],
+    out: &mut impl Write,
+) -> io::Result<()> {
+    match shell {
+        Shell::Bash => generate_bash(bin_name, commands, out),
+        Shell::Zsh => generate_zsh(bin_name, commands, out),
+        Shell::Fish => generate_fish(bin_name, commands, out),
+        Shell::Elvish => generate_elvish(bin_name, commands, out),
+        Shell::PowerShell => generate_powershell(bin_name, commands, out),
+    }
+}
+
+/// Whether a flag of this type expects an argument after it, as opposed to a bare switch.
+fn takes_argument(flag_type: FlagType) -> bool {
+    matches!(
+        flag_type,
+        FlagType::String | FlagType::Int | FlagType::Float
+    )
+}
+
+/// Escape characters that would otherwise break out of a zsh `_arguments` spec's quoted
+/// `'--flag[description]'` entry: the quote delimiting the whole spec, and the brackets
+/// delimiting the description.
+fn escape_zsh(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\'', "'\\''")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+}
+
+/// Escape characters that would otherwise break out of a fish `-d "description"` double-quoted
+/// string.
+fn escape_fish(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn generate_bash(
+    bin_name: &str,
+    commands: &[(&str, &[Flag])],
+    out: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(out, "_{bin_name}_completions() {{")?;
+    writeln!(out, "    local cur prev\n    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"\n    prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"")?;
+    for (name, flags) in commands {
+        writeln!(
+            out,
+            "    if [[ \"${{COMP_WORDS[1]}}\" == \"{name}\" ]]; then"
+        )?;
+        let long_flags: Vec<String> = flags.iter().map(|f| format!("--{}", f.name)).collect();
+        writeln!(
+            out,
+            "        COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))",
+            long_flags.join(" ")
+        )?;
+        for flag in flags.iter().filter(|f| takes_argument(f.flag_type)) {
+            writeln!(
+                out,
+                "        [[ \"$prev\" == \"--{}\" ]] && COMPREPLY=( \"<{}>\" )",
+                flag.name, flag.name
+            )?;
+        }
+        writeln!(out, "        return\n    fi")?;
+    }
+    let subcommand_names: Vec<&str> = commands.iter().map(|(name, _)| *name).collect();
+    writeln!(
+        out,
+        "    COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))",
+        subcommand_names.join(" ")
+    )?;
+    writeln!(out, "}}")?;
+    writeln!(out, "complete -F _{bin_name}_completions {bin_name}")
+}
+
+fn generate_zsh(
+    bin_name: &str,
+    commands: &[(&str, &[Flag])],
+    out: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(out, "#compdef {bin_name}")?;
+    writeln!(out, "_{bin_name}() {{")?;
+    writeln!(out, "    local -a subcommands")?;
+    writeln!(out, "    subcommands=(")?;
+    for (name, _) in commands {
+        writeln!(out, "        '{name}'")?;
+    }
+    writeln!(out, "    )")?;
+    writeln!(out, "    _arguments -C \\")?;
+    writeln!(out, "        '1: :->command' \\")?;
+    writeln!(out, "        '*:: :->args'")?;
+    writeln!(out, "    case $state in")?;
+    writeln!(out, "        command) _describe 'command' subcommands ;;")?;
+    writeln!(out, "        args)")?;
+    writeln!(out, "            case $words[1] in")?;
+    for (name, flags) in commands {
+        writeln!(out, "                {name})")?;
+        writeln!(out, "                    _arguments \\")?;
+        for flag in flags.iter() {
+            writeln!(
+                out,
+                "                        '--{}[{}]' \\",
+                flag.name,
+                escape_zsh(flag.usage)
+            )?;
+        }
+        writeln!(out, "                    ;;")?;
+    }
+    writeln!(out, "            esac")?;
+    writeln!(out, "            ;;")?;
+    writeln!(out, "    esac")?;
+    writeln!(out, "}}")?;
+    writeln!(out, "_{bin_name}")
+}
+
+fn generate_fish(
+    bin_name: &str,
+    commands: &[(&str, &[Flag])],
+    out: &mut impl Write,
+) -> io::Result<()> {
+    for (name, _) in commands {
+        writeln!(
+            out,
+            "complete -c {bin_name} -n \"__fish_use_subcommand\" -a {name}"
+        )?;
+    }
+    for (name, flags) in commands {
+        for flag in flags.iter() {
+            writeln!(
+                out,
+                "complete -c {bin_name} -n \"__fish_seen_subcommand_from {name}\" -l {} -d \"{}\"",
+                flag.name,
+                escape_fish(flag.usage)
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn generate_elvish(
+    bin_name: &str,
+    commands: &[(&str, &[Flag])],
+    out: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "edit:completion:arg-completer[{bin_name}] = [@words]{{"
+    )?;
+    writeln!(out, "    if (eq (count $words) 2) {{")?;
+    for (name, _) in commands {
+        writeln!(out, "        put {name}")?;
+    }
+    writeln!(out, "    }}")?;
+    for (name, flags) in commands {
+        writeln!(out, "    if (eq $words[1] {name}) {{")?;
+        for flag in flags.iter() {
+            writeln!(out, "        put --{}", flag.name)?;
+        }
+        writeln!(out, "    }}")?;
+    }
+    writeln!(out, "}}")
+}
+
+fn generate_powershell(
+    bin_name: &str,
+    commands: &[(&str, &[Flag])],
+    out: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "Register-ArgumentCompleter -Native -CommandName {bin_name} -ScriptBlock {{"
+    )?;
+    writeln!(
+        out,
+        "    param($wordToComplete, $commandAst, $cursorPosition)"
+    )?;
+    writeln!(out, "    if ($commandAst.CommandElements.Count -le 2) {{")?;
+    for (name, _) in commands {
+        writeln!(out, "        '{name}'")?;
+    }
+    writeln!(out, "    }}")?;
+    for (name, flags) in commands {
+        writeln!(
+            out,
+            "    if ($commandAst.CommandElements[1].Value -eq '{name}') {{"
+        )?;
+        for flag in flags.iter() {
+            writeln!(out, "        '--{}'", flag.name)?;
+        }
+        writeln!(out, "    }}")?;
+    }
+    writeln!(out, "}}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FlagType;
+
+    fn render(shell: Shell, commands: &[(&str, &[Flag])]) -> String {
+        let mut out = Vec::new();
+        generate_completions(shell, "mycli", commands, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn bash_lists_subcommand_names() {
+        let commands: Vec<(&str, &[Flag])> = vec![("run", &[]), ("build", &[])];
+        let script = render(Shell::Bash, &commands);
+        assert!(script.contains("run build"));
+    }
+
+    #[test]
+    fn zsh_lists_subcommand_names() {
+        let commands: Vec<(&str, &[Flag])> = vec![("run", &[]), ("build", &[])];
+        let script = render(Shell::Zsh, &commands);
+        assert!(script.contains("'run'"));
+        assert!(script.contains("'build'"));
+    }
+
+    #[test]
+    fn fish_lists_subcommand_names() {
+        let commands: Vec<(&str, &[Flag])> = vec![("run", &[]), ("build", &[])];
+        let script = render(Shell::Fish, &commands);
+        assert!(script.contains("-a run"));
+        assert!(script.contains("-a build"));
+    }
+
+    #[test]
+    fn elvish_lists_subcommand_names_as_first_completion() {
+        let commands: Vec<(&str, &[Flag])> = vec![("run", &[]), ("build", &[])];
+        let script = render(Shell::Elvish, &commands);
+        assert!(script.contains("if (eq (count $words) 2)"));
+        assert!(script.contains("put run"));
+        assert!(script.contains("put build"));
+    }
+
+    #[test]
+    fn powershell_lists_subcommand_names_as_first_completion() {
+        let commands: Vec<(&str, &[Flag])> = vec![("run", &[]), ("build", &[])];
+        let script = render(Shell::PowerShell, &commands);
+        assert!(script.contains("CommandElements.Count -le 2"));
+        assert!(script.contains("'run'"));
+        assert!(script.contains("'build'"));
+    }
+
+    #[test]
+    fn zsh_escapes_brackets_in_usage() {
+        let flags = [Flag::new(
+            "string",
+            "cli cmd [arg] --string [string]",
+            FlagType::String,
+        )];
+        let commands: Vec<(&str, &[Flag])> = vec![("run", &flags)];
+        let script = render(Shell::Zsh, &commands);
+        assert!(script.contains("'--string[cli cmd \\[arg\\] --string \\[string\\]]' \\"));
+    }
+
+    #[test]
+    fn fish_escapes_quotes_in_usage() {
+        let flags = [Flag::new("string", "say \"hi\"", FlagType::String)];
+        let commands: Vec<(&str, &[Flag])> = vec![("run", &flags)];
+        let script = render(Shell::Fish, &commands);
+        assert!(script.contains("-d \"say \\\"hi\\\"\""));
+    }
+}
@@ -1,4 +1,223 @@
 use crate::{Flag, FlagType, FlagValue};
+use std::collections::BTreeMap;
+#[cfg(any(feature = "config_toml", feature = "config_json"))]
+use std::path::Path;
+
+/// A tag for the shape a [`ContextValue`] is expected to have, used by [`parse_string`] to know
+/// how to coerce a raw string into a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    String,
+    Int,
+    Float,
+    Bool,
+    Map,
+    Array,
+}
+
+/// A value reachable through [`Context::get_value`]: either a scalar, or a nested `Map`/`Array`
+/// of more `ContextValue`s.
+///
+/// This is the structured counterpart to [`FlagValue`]'s four scalar variants, and is what a
+/// config file is deserialized into so that keys not tied to any declared `Flag` are still
+/// reachable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextValue {
+    String(String),
+    Int(isize),
+    Float(f64),
+    Bool(bool),
+    Map(BTreeMap<String, ContextValue>),
+    Array(Vec<ContextValue>),
+}
+
+impl ContextValue {
+    /// Borrow the inner string, or `None` if this isn't a `String`.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use seahorse::ContextValue;
+    ///
+    /// let value = ContextValue::String("hello".to_string());
+    /// assert_eq!(Some("hello"), value.as_string());
+    /// ```
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            ContextValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Copy out the inner int, or `None` if this isn't an `Int`.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use seahorse::ContextValue;
+    ///
+    /// let value = ContextValue::Int(100);
+    /// assert_eq!(Some(100), value.as_int());
+    /// ```
+    pub fn as_int(&self) -> Option<isize> {
+        match self {
+            ContextValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Copy out the inner float, or `None` if this isn't a `Float`.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use seahorse::ContextValue;
+    ///
+    /// let value = ContextValue::Float(1.23);
+    /// assert_eq!(Some(1.23), value.as_float());
+    /// ```
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            ContextValue::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// Copy out the inner bool, or `None` if this isn't a `Bool`.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use seahorse::ContextValue;
+    ///
+    /// let value = ContextValue::Bool(true);
+    /// assert_eq!(Some(true), value.as_bool());
+    /// ```
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ContextValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Borrow the inner map, or `None` if this isn't a `Map`.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use seahorse::ContextValue;
+    ///
+    /// let value = ContextValue::Map(Default::default());
+    /// assert!(value.as_map().is_some());
+    /// ```
+    pub fn as_map(&self) -> Option<&BTreeMap<String, ContextValue>> {
+        match self {
+            ContextValue::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Borrow the inner array, or `None` if this isn't an `Array`.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use seahorse::ContextValue;
+    ///
+    /// let value = ContextValue::Array(vec![ContextValue::Int(1)]);
+    /// assert_eq!(1, value.as_array().unwrap().len());
+    /// ```
+    pub fn as_array(&self) -> Option<&Vec<ContextValue>> {
+        match self {
+            ContextValue::Array(arr) => Some(arr),
+            _ => None,
+        }
+    }
+
+    /// Coerce this value into the `FlagValue` variant it already looks like.
+    ///
+    /// Maps resolve to `None`, and so do arrays: they are not converted into a `FlagValue::List`.
+    #[cfg(any(feature = "config_toml", feature = "config_json"))]
+    fn to_flag_value(&self) -> Option<FlagValue> {
+        match self {
+            ContextValue::String(s) => Some(FlagValue::String(s.to_string())),
+            ContextValue::Int(i) => Some(FlagValue::Int(*i)),
+            ContextValue::Float(f) => Some(FlagValue::Float(*f)),
+            ContextValue::Bool(b) => Some(FlagValue::Bool(*b)),
+            ContextValue::Map(_) | ContextValue::Array(_) => None,
+        }
+    }
+}
+
+impl From<FlagValue> for ContextValue {
+    /// A `FlagValue::List` has no scalar `ContextValue` equivalent, so it becomes an `Array` of
+    /// its (recursively converted) elements.
+    fn from(value: FlagValue) -> Self {
+        match value {
+            FlagValue::Bool(b) => ContextValue::Bool(b),
+            FlagValue::String(s) => ContextValue::String(s),
+            FlagValue::Int(i) => ContextValue::Int(i),
+            FlagValue::Float(f) => ContextValue::Float(f),
+            FlagValue::List(values) => {
+                ContextValue::Array(values.into_iter().map(ContextValue::from).collect())
+            }
+        }
+    }
+}
+
+/// Coerce a raw string (from the command line or a config file) into the requested `ValueKind`.
+///
+/// `Map` and `Array` have no string representation and always fail to parse.
+///
+/// Example
+///
+/// ```
+/// use seahorse::{parse_string, ContextValue, ValueKind};
+///
+/// assert_eq!(Ok(ContextValue::Int(100)), parse_string("100", ValueKind::Int));
+/// assert!(parse_string("not a number", ValueKind::Int).is_err());
+/// ```
+pub fn parse_string(raw: &str, kind: ValueKind) -> Result<ContextValue, String> {
+    match kind {
+        ValueKind::String => Ok(ContextValue::String(raw.to_string())),
+        ValueKind::Int => raw
+            .parse()
+            .map(ContextValue::Int)
+            .map_err(|_| format!("cannot parse `{}` as an int", raw)),
+        ValueKind::Float => raw
+            .parse()
+            .map(ContextValue::Float)
+            .map_err(|_| format!("cannot parse `{}` as a float", raw)),
+        ValueKind::Bool => raw
+            .parse()
+            .map(ContextValue::Bool)
+            .map_err(|_| format!("cannot parse `{}` as a bool", raw)),
+        ValueKind::Map | ValueKind::Array => Err(format!("cannot parse `{}` as a {:?}", raw, kind)),
+    }
+}
+
+/// A parsed config file, keyed by top level name.
+///
+/// Nested tables are reached with a dotted path, e.g. `"server.port"`.
+#[cfg(any(feature = "config_toml", feature = "config_json"))]
+pub type ConfigMap = BTreeMap<String, ContextValue>;
+
+/// Where a flag's resolved value ultimately came from, in precedence order.
+///
+/// Exposed through `Context::value_source` so callers can debug (or just log) why a flag ended
+/// up with the value it has, without having to re-derive the CLI/env/config chain. There is no
+/// `Default` variant: `Flag` carries no default value, so a flag with no command line,
+/// environment or config file value has no resolved value at all, and `value_source` reports
+/// `None` for it just as for a flag that was never declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueSource {
+    CommandLine,
+    Environment,
+    ConfigFile,
+}
+
+/// A flag's resolved name, value, and where that value came from
+type FlagEntry = (String, Result<FlagValue, String>, ValueSource);
 
 /// `Context` type
 ///
@@ -6,14 +225,31 @@ use crate::{Flag, FlagType, FlagValue};
 pub struct Context {
     /// `Vec<String>` with flags and flag values ​​removed from command line arguments
     pub args: Vec<String>,
-    /// `Vec` that stores flag name and flag value as tuple
-    flags: Option<Vec<(String, Result<FlagValue, String>)>>,
+    /// `Vec` that stores flag name, flag value, and where that value was resolved from
+    flags: Option<Vec<FlagEntry>>,
+    /// Values loaded from a `--config` file, consulted when a flag has no CLI or env occurrence.
+    /// `Some(Err(..))` means `--config` was given but the file couldn't be read or parsed; that
+    /// error is surfaced (rather than silently discarded) by any flag that falls back to it.
+    #[cfg(any(feature = "config_toml", feature = "config_json"))]
+    config: Option<Result<ConfigMap, String>>,
 }
 
 impl Context {
     /// Create new instance of `Context`
     /// Parse processing using `Vec<String>` command line argument and `Vec<Flag>` as arguments
     ///
+    /// A `--config <path>` flag is recognized before flag parsing begins: when present (and the
+    /// `config_toml` or `config_json` feature is enabled), its file is loaded and consulted by
+    /// flags that have no value on the command line or in the environment.
+    ///
+    /// A `Flag` marked `multiple` may occur more than once; every occurrence is parsed and
+    /// collected, in command-line order, into a single `FlagValue::List` (see `list_flag`).
+    ///
+    /// A `Flag` with `.env(name)` set is consulted when it has no command line occurrence,
+    /// before config. Resolution order is therefore: command line > environment variable >
+    /// config file, and `value_source` reports which one won. `Flag` has no default value, so a
+    /// flag found in none of these has no value and `value_source` reports `None` for it.
+    ///
     /// Example
     ///
     /// ```
@@ -27,22 +263,48 @@ impl Context {
     pub fn new(args: Vec<String>, flags: Option<Vec<Flag>>) -> Self {
         let mut v = Vec::new();
         let mut parsed_args = args.clone();
+
+        #[cfg(any(feature = "config_toml", feature = "config_json"))]
+        let config = Self::load_config(&mut parsed_args);
+
         let flags_val = match flags {
             Some(flags) => {
                 for flag in flags {
-                    if let Some(index) = flag.option_index(&parsed_args) {
-                        parsed_args.remove(index);
-
-                        let val = if flag.flag_type != FlagType::Bool {
-                            if parsed_args.is_empty() {
-                                None
-                            } else {
-                                Some(parsed_args.remove(index))
+                    if flag.multiple {
+                        let mut occurrences = Vec::new();
+                        let mut error = None;
+                        while let Some(index) = flag.option_index(&parsed_args) {
+                            parsed_args.remove(index);
+                            let val = Self::take_flag_value(&flag, &mut parsed_args, index);
+                            match flag.value(val) {
+                                Ok(value) => occurrences.push(value),
+                                Err(e) => {
+                                    error = Some(e);
+                                    break;
+                                }
                             }
-                        } else {
-                            None
-                        };
-                        v.push((flag.name.to_string(), flag.value(val)))
+                        }
+                        if let Some(e) = error {
+                            v.push((flag.name.to_string(), Err(e), ValueSource::CommandLine));
+                        } else if !occurrences.is_empty() {
+                            v.push((
+                                flag.name.to_string(),
+                                Ok(FlagValue::List(occurrences)),
+                                ValueSource::CommandLine,
+                            ));
+                        } else if let Some(value) = Self::env_flag_value(&flag) {
+                            v.push((flag.name.to_string(), value, ValueSource::Environment));
+                        }
+                    } else if let Some(index) = flag.option_index(&parsed_args) {
+                        parsed_args.remove(index);
+                        let val = Self::take_flag_value(&flag, &mut parsed_args, index);
+                        v.push((
+                            flag.name.to_string(),
+                            flag.value(val),
+                            ValueSource::CommandLine,
+                        ))
+                    } else if let Some(value) = Self::env_flag_value(&flag) {
+                        v.push((flag.name.to_string(), value, ValueSource::Environment));
                     }
                 }
                 Some(v)
@@ -53,11 +315,201 @@ impl Context {
         Self {
             args: parsed_args,
             flags: flags_val,
+            #[cfg(any(feature = "config_toml", feature = "config_json"))]
+            config,
+        }
+    }
+
+    /// Resolve a flag from its associated environment variable, if it has one set and that
+    /// variable is present.
+    ///
+    /// Bool flags are parsed from the variable's content (`"1"`, `"true"` or `"yes"`, in any
+    /// case, vs. anything else)
+    /// rather than just its presence, since unlike a bare CLI switch, an env var can carry an
+    /// explicit `false`. Other types are parsed through the same `Flag::value` path used for
+    /// CLI occurrences.
+    fn env_flag_value(flag: &Flag) -> Option<Result<FlagValue, String>> {
+        let env_name = flag.env?;
+        let raw = std::env::var(env_name).ok()?;
+        if flag.flag_type == FlagType::Bool {
+            let enabled = matches!(raw.to_lowercase().as_str(), "1" | "true" | "yes");
+            Some(Ok(FlagValue::Bool(enabled)))
+        } else {
+            Some(flag.value(Some(raw)))
+        }
+    }
+
+    /// Remove and return the value token following a flag occurrence at `index`, or `None` for
+    /// bool flags (which carry no value) and for a value flag left with nothing after it.
+    fn take_flag_value(flag: &Flag, parsed_args: &mut Vec<String>, index: usize) -> Option<String> {
+        if flag.flag_type != FlagType::Bool {
+            if index >= parsed_args.len() {
+                None
+            } else {
+                Some(parsed_args.remove(index))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Pull a `--config <path>` occurrence out of the raw args (if present) and load it.
+    ///
+    /// The file is parsed according to its extension: `.toml` requires the `config_toml`
+    /// feature, `.json` requires `config_json`. A missing file, an IO error, a malformed file,
+    /// or an unsupported extension is retained as `Some(Err(..))` rather than discarded, so
+    /// flags that fall back to the file can report it instead of silently resolving to no
+    /// value.
+    #[cfg(any(feature = "config_toml", feature = "config_json"))]
+    fn load_config(parsed_args: &mut Vec<String>) -> Option<Result<ConfigMap, String>> {
+        let index = parsed_args.iter().position(|a| a == "--config")?;
+        parsed_args.remove(index);
+        if index >= parsed_args.len() {
+            return Some(Err("--config requires a path".to_string()));
+        }
+        let path = parsed_args.remove(index);
+        Some(Self::load_config_file(Path::new(&path)))
+    }
+
+    #[cfg(any(feature = "config_toml", feature = "config_json"))]
+    fn load_config_file(path: &Path) -> Result<ConfigMap, String> {
+        let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "config_toml")]
+            Some("toml") => {
+                let table: toml::Value = toml::from_str(&contents).map_err(|e| e.to_string())?;
+                Ok(Self::toml_table_to_config_map(table))
+            }
+            #[cfg(feature = "config_json")]
+            Some("json") => {
+                let value: serde_json::Value =
+                    serde_json::from_str(&contents).map_err(|e| e.to_string())?;
+                Ok(Self::json_value_to_config_map(value))
+            }
+            Some(ext) => Err(format!("unsupported config file extension: {}", ext)),
+            None => Err("config file has no extension".to_string()),
+        }
+    }
+
+    #[cfg(feature = "config_toml")]
+    fn toml_table_to_config_map(value: toml::Value) -> ConfigMap {
+        match value {
+            toml::Value::Table(table) => table
+                .into_iter()
+                .map(|(k, v)| (k, Self::toml_value_to_context_value(v)))
+                .collect(),
+            _ => ConfigMap::new(),
+        }
+    }
+
+    #[cfg(feature = "config_toml")]
+    fn toml_value_to_context_value(value: toml::Value) -> ContextValue {
+        match value {
+            toml::Value::String(s) => ContextValue::String(s),
+            toml::Value::Integer(i) => ContextValue::Int(i as isize),
+            toml::Value::Float(f) => ContextValue::Float(f),
+            toml::Value::Boolean(b) => ContextValue::Bool(b),
+            toml::Value::Array(arr) => ContextValue::Array(
+                arr.into_iter()
+                    .map(Self::toml_value_to_context_value)
+                    .collect(),
+            ),
+            toml::Value::Table(_) => ContextValue::Map(Self::toml_table_to_config_map(value)),
+            toml::Value::Datetime(dt) => ContextValue::String(dt.to_string()),
+        }
+    }
+
+    /// A JSON `null` carries no value of any `ContextValue` kind, so keys holding it are dropped
+    /// rather than coerced into a misleading scalar.
+    #[cfg(feature = "config_json")]
+    fn json_value_to_config_map(value: serde_json::Value) -> ConfigMap {
+        match value {
+            serde_json::Value::Object(map) => map
+                .into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, Self::json_value_to_context_value(v)))
+                .collect(),
+            _ => ConfigMap::new(),
+        }
+    }
+
+    /// Converts a non-null JSON value; `null` is filtered out by the caller before array/object
+    /// elements reach here, so it is never expected as input.
+    #[cfg(feature = "config_json")]
+    fn json_value_to_context_value(value: serde_json::Value) -> ContextValue {
+        match value {
+            serde_json::Value::String(s) => ContextValue::String(s),
+            serde_json::Value::Bool(b) => ContextValue::Bool(b),
+            serde_json::Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    ContextValue::Int(i as isize)
+                } else {
+                    ContextValue::Float(n.as_f64().unwrap_or_default())
+                }
+            }
+            serde_json::Value::Array(arr) => ContextValue::Array(
+                arr.into_iter()
+                    .filter(|v| !v.is_null())
+                    .map(Self::json_value_to_context_value)
+                    .collect(),
+            ),
+            serde_json::Value::Object(_) => {
+                ContextValue::Map(Self::json_value_to_config_map(value))
+            }
+            serde_json::Value::Null => ContextValue::String(String::new()),
+        }
+    }
+
+    /// Look up a dotted path (e.g. `"server.port"`) inside a `ConfigMap`, descending into maps.
+    #[cfg(any(feature = "config_toml", feature = "config_json"))]
+    fn lookup_config<'a>(config: &'a ConfigMap, dotted: &str) -> Option<&'a ContextValue> {
+        let mut segments = dotted.split('.');
+        let mut current = config.get(segments.next()?)?;
+        for segment in segments {
+            match current {
+                ContextValue::Map(map) => current = map.get(segment)?,
+                _ => return None,
+            }
+        }
+        Some(current)
+    }
+
+    /// Get a value by dotted path (e.g. `"server.ports"`), descending into nested maps.
+    ///
+    /// This is a uniform way to read both the existing typed flags and richer structured
+    /// values: a declared `Flag`'s resolved value (from the command line, environment, or
+    /// config) wins when present, converted into the matching `ContextValue` variant;
+    /// otherwise a raw config entry at `name` is returned as-is, including `Map`/`Array` shapes
+    /// that have no `FlagType` equivalent and so no dedicated typed accessor.
+    pub fn get_value(&self, name: &str) -> Option<ContextValue> {
+        let flag_value = self
+            .flags
+            .as_ref()
+            .and_then(|flags| flags.iter().find(|flag| flag.0 == name))
+            .and_then(|flag| flag.1.as_ref().ok().cloned());
+
+        if let Some(value) = flag_value {
+            return Some(value.into());
+        }
+
+        #[cfg(any(feature = "config_toml", feature = "config_json"))]
+        {
+            let config = self.config.as_ref()?.as_ref().ok()?;
+            Self::lookup_config(config, name).cloned()
+        }
+        #[cfg(not(any(feature = "config_toml", feature = "config_json")))]
+        {
+            None
         }
     }
 
     /// Get flag value
-    fn result_flag_value<'a>(&self, name: &str) -> Option<Result<FlagValue, String>> {
+    ///
+    /// Resolution order: a command line occurrence wins; then an environment variable; then,
+    /// when a config file was loaded, its value (if any); flags with none of those resolve to
+    /// `None`, since `Flag` has no default value.
+    fn result_flag_value(&self, name: &str) -> Option<Result<FlagValue, String>> {
         let flag = self
             .flags
             .as_ref()
@@ -65,10 +517,57 @@ impl Context {
 
         match flag {
             Some(f) => Some(f.1.to_owned()),
+            None => self.config_flag_value(name),
+        }
+    }
+
+    /// Where the value returned for `name` by the `*_flag` accessors came from.
+    ///
+    /// `None` covers both an undeclared flag and one with no command line, environment or config
+    /// file value — see [`ValueSource`] for why those two cases aren't distinguished.
+    pub fn value_source(&self, name: &str) -> Option<ValueSource> {
+        let flag = self
+            .flags
+            .as_ref()
+            .and_then(|flags| flags.iter().find(|flag| flag.0 == name));
+
+        match flag {
+            Some(f) => Some(f.2),
+            None if self.config_contains(name) => Some(ValueSource::ConfigFile),
             None => None,
         }
     }
 
+    /// Whether `name` exists in the loaded config file, regardless of whether its value is a
+    /// scalar `FlagValue` can represent. Checking existence this way (rather than going through
+    /// `config_flag_value`'s scalar-only conversion) keeps `value_source` accurate for `Map`/
+    /// `Array` entries, which `get_value` can return but no `*_flag` accessor can.
+    #[cfg(any(feature = "config_toml", feature = "config_json"))]
+    fn config_contains(&self, name: &str) -> bool {
+        match self.config.as_ref() {
+            Some(Ok(config)) => Self::lookup_config(config, name).is_some(),
+            _ => false,
+        }
+    }
+
+    #[cfg(not(any(feature = "config_toml", feature = "config_json")))]
+    fn config_contains(&self, _name: &str) -> bool {
+        false
+    }
+
+    #[cfg(any(feature = "config_toml", feature = "config_json"))]
+    fn config_flag_value(&self, name: &str) -> Option<Result<FlagValue, String>> {
+        match self.config.as_ref()? {
+            Ok(config) => Self::lookup_config(config, name)?.to_flag_value().map(Ok),
+            Err(e) => Some(Err(e.clone())),
+        }
+    }
+
+    #[cfg(not(any(feature = "config_toml", feature = "config_json")))]
+    fn config_flag_value(&self, _name: &str) -> Option<Result<FlagValue, String>> {
+        None
+    }
+
     /// Get bool flag
     ///
     /// Example
@@ -110,10 +609,10 @@ impl Context {
     ///     Err(_) => println!("Not found string...")
     /// }
     /// ```
-    pub fn string_flag(&self, name: &str) -> Option<Result<String, String>> {
+    pub fn string_flag(&self, name: &str) -> Result<String, String> {
         match self.result_flag_value(name) {
-            Ok(FlagValue::String(val)) => Ok(val.to_string()),
-            Err(e) => Err(e.to_owned()),
+            Some(Ok(FlagValue::String(val))) => Ok(val),
+            Some(Err(e)) => Err(e),
             _ => Err("".to_string()),
         }
     }
@@ -137,8 +636,8 @@ impl Context {
     /// ```
     pub fn int_flag(&self, name: &str) -> Result<isize, String> {
         match self.result_flag_value(name) {
-            Ok(FlagValue::Int(val)) => Ok(val),
-            Err(e) => Err(e.to_owned()),
+            Some(Ok(FlagValue::Int(val))) => Ok(val),
+            Some(Err(e)) => Err(e),
             _ => Err("hoge".to_string()),
         }
     }
@@ -162,15 +661,67 @@ impl Context {
     /// ```
     pub fn float_flag(&self, name: &str) -> Result<f64, String> {
         match self.result_flag_value(name) {
-            Ok(FlagValue::Float(val)) => Ok(val),
-            Err(e) => Err(e.to_owned()),
+            Some(Ok(FlagValue::Float(val))) => Ok(val),
+            Some(Err(e)) => Err(e),
             _ => Err("hoge".to_string()),
         }
     }
+
+    /// Get a flag declared with `multiple`, collected in the order it occurred on the command
+    /// line.
+    ///
+    /// A flag value that was resolved from a single occurrence (or from environment/config) is
+    /// wrapped in a one-element `Vec` so callers don't need to special-case non-repeated input.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use std::env;
+    /// use seahorse::{Context, Flag, FlagType};
+    ///
+    /// let args: Vec<String> = env::args().collect();
+    /// let flag = Flag::new("tag", "cli cmd [arg] --tag [tag]...", FlagType::String).multiple();
+    /// let context = Context::new(args, Some(vec![flag]));
+    ///
+    /// match context.list_flag("tag") {
+    ///     Some(Ok(tags)) => println!("{} tag(s)", tags.len()),
+    ///     _ => println!("Not found tag..."),
+    /// }
+    /// ```
+    pub fn list_flag(&self, name: &str) -> Option<Result<Vec<FlagValue>, String>> {
+        match self.result_flag_value(name)? {
+            Ok(FlagValue::List(values)) => Some(Ok(values)),
+            Ok(other) => Some(Ok(vec![other])),
+            Err(e) => Some(Err(e)),
+        }
+    }
 }
 #[cfg(test)]
 mod tests {
-    use crate::{Context, Flag, FlagType};
+    use crate::{Context, ContextValue, Flag, FlagType, ValueSource};
+
+    #[test]
+    fn get_value_falls_back_to_flag() {
+        let args = vec![
+            "cli".to_string(),
+            "command".to_string(),
+            "--string".to_string(),
+            "test".to_string(),
+        ];
+        let flags = vec![Flag::new("string", "", FlagType::String)];
+        let context = Context::new(args, Some(flags));
+
+        assert_eq!(
+            Some(ContextValue::String("test".to_string())),
+            context.get_value("string")
+        );
+    }
+
+    #[test]
+    fn get_value_unknown_name_is_none() {
+        let context = Context::new(vec!["cli".to_string()], Some(vec![]));
+        assert_eq!(None, context.get_value("missing"));
+    }
 
     #[test]
     fn context_test() {
@@ -194,26 +745,25 @@ mod tests {
         ];
         let context = Context::new(args, Some(flags));
 
-        assert_eq!(true, context.bool_flag("bool"));
+        assert!(context.bool_flag("bool"));
 
         match context.string_flag("string") {
             Ok(val) => assert_eq!("test".to_string(), val),
-            _ => assert!(false),
+            _ => panic!("expected string flag to be set"),
         }
 
         match context.int_flag("int") {
             Ok(val) => assert_eq!(100, val),
-            _ => assert!(false),
+            _ => panic!("expected int flag to be set"),
         }
 
         match context.float_flag("float") {
             Ok(val) => assert_eq!(1.23, val),
-            _ => assert!(false),
+            _ => panic!("expected float flag to be set"),
         }
     }
 
     #[test]
-    #[should_panic]
     fn argument_fail() {
         let args = vec![
             "cli".to_string(),
@@ -227,6 +777,225 @@ mod tests {
             Flag::new("string", "", FlagType::String),
         ];
 
-        Context::new(args, Some(flags));
+        let context = Context::new(args, Some(flags));
+
+        assert_eq!(
+            Err("Flag needs a value".to_string()),
+            context.string_flag("string")
+        );
+    }
+
+    #[test]
+    fn value_source_reports_command_line() {
+        let args = vec![
+            "cli".to_string(),
+            "command".to_string(),
+            "--string".to_string(),
+            "test".to_string(),
+        ];
+        let flags = vec![Flag::new("string", "", FlagType::String)];
+        let context = Context::new(args, Some(flags));
+
+        assert_eq!(
+            Some(ValueSource::CommandLine),
+            context.value_source("string")
+        );
+    }
+
+    #[test]
+    fn value_source_is_none_for_undeclared_flag() {
+        let context = Context::new(vec!["cli".to_string()], Some(vec![]));
+        assert_eq!(None, context.value_source("string"));
+    }
+
+    #[test]
+    fn env_fallback_used_when_no_command_line_occurrence() {
+        std::env::set_var("SEAHORSE_TEST_TOKEN", "from-env");
+        let flags = vec![Flag::new("token", "", FlagType::String).env("SEAHORSE_TEST_TOKEN")];
+        let context = Context::new(vec!["cli".to_string()], Some(flags));
+
+        assert_eq!(Ok("from-env".to_string()), context.string_flag("token"));
+        assert_eq!(
+            Some(ValueSource::Environment),
+            context.value_source("token")
+        );
+        std::env::remove_var("SEAHORSE_TEST_TOKEN");
+    }
+
+    #[test]
+    fn env_bool_fallback_parses_value_rather_than_just_presence() {
+        std::env::set_var("SEAHORSE_TEST_FLAG", "false");
+        let flags = vec![Flag::new("flag", "", FlagType::Bool).env("SEAHORSE_TEST_FLAG")];
+        let context = Context::new(vec!["cli".to_string()], Some(flags));
+
+        assert!(!context.bool_flag("flag"));
+        std::env::remove_var("SEAHORSE_TEST_FLAG");
+    }
+
+    #[test]
+    fn multiple_flag_collects_every_occurrence() {
+        let args = vec![
+            "cli".to_string(),
+            "command".to_string(),
+            "--tag".to_string(),
+            "a".to_string(),
+            "--tag".to_string(),
+            "b".to_string(),
+        ];
+        let flags = vec![Flag::new("tag", "", FlagType::String).multiple()];
+        let context = Context::new(args, Some(flags));
+
+        match context.list_flag("tag") {
+            Some(Ok(values)) => assert_eq!(
+                vec![
+                    crate::FlagValue::String("a".to_string()),
+                    crate::FlagValue::String("b".to_string())
+                ],
+                values
+            ),
+            _ => panic!("expected tag flag to collect both occurrences"),
+        }
+    }
+
+    #[test]
+    fn list_flag_wraps_a_non_multiple_flag_in_a_one_element_vec() {
+        let args = vec![
+            "cli".to_string(),
+            "command".to_string(),
+            "--tag".to_string(),
+            "a".to_string(),
+        ];
+        let flags = vec![Flag::new("tag", "", FlagType::String)];
+        let context = Context::new(args, Some(flags));
+
+        match context.list_flag("tag") {
+            Some(Ok(values)) => assert_eq!(vec![crate::FlagValue::String("a".to_string())], values),
+            _ => panic!("expected tag flag to be set"),
+        }
+    }
+
+    #[cfg(feature = "config_json")]
+    fn write_temp_config(test_name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "seahorse_test_{}_{}.json",
+            test_name,
+            std::process::id()
+        ));
+        std::fs::write(&path, contents).expect("failed to write temp config file");
+        path
+    }
+
+    #[cfg(feature = "config_json")]
+    #[test]
+    fn config_file_is_overridden_by_command_line() {
+        let path = write_temp_config(
+            "precedence",
+            r#"{"string": "from-config", "server": {"port": 8080}}"#,
+        );
+        let args = vec![
+            "cli".to_string(),
+            "--config".to_string(),
+            path.to_str().unwrap().to_string(),
+            "--string".to_string(),
+            "from-cli".to_string(),
+        ];
+        let flags = vec![Flag::new("string", "", FlagType::String)];
+        let context = Context::new(args, Some(flags));
+
+        assert_eq!(Ok("from-cli".to_string()), context.string_flag("string"));
+        assert_eq!(
+            Some(ValueSource::CommandLine),
+            context.value_source("string")
+        );
+        std::fs::remove_file(path).ok();
+    }
+
+    #[cfg(feature = "config_json")]
+    #[test]
+    fn config_file_backs_a_flag_with_no_command_line_occurrence() {
+        let path = write_temp_config("fallback", r#"{"string": "from-config"}"#);
+        let args = vec![
+            "cli".to_string(),
+            "--config".to_string(),
+            path.to_str().unwrap().to_string(),
+        ];
+        let flags = vec![Flag::new("string", "", FlagType::String)];
+        let context = Context::new(args, Some(flags));
+
+        assert_eq!(Ok("from-config".to_string()), context.string_flag("string"));
+        assert_eq!(
+            Some(ValueSource::ConfigFile),
+            context.value_source("string")
+        );
+        std::fs::remove_file(path).ok();
+    }
+
+    #[cfg(feature = "config_json")]
+    #[test]
+    fn get_value_reads_a_nested_config_map() {
+        let path = write_temp_config("nested", r#"{"server": {"port": 8080}}"#);
+        let args = vec![
+            "cli".to_string(),
+            "--config".to_string(),
+            path.to_str().unwrap().to_string(),
+        ];
+        let context = Context::new(args, Some(vec![]));
+
+        assert_eq!(
+            Some(ContextValue::Int(8080)),
+            context.get_value("server.port")
+        );
+        std::fs::remove_file(path).ok();
+    }
+
+    #[cfg(feature = "config_json")]
+    #[test]
+    fn value_source_reports_config_file_for_a_map_valued_entry() {
+        let path = write_temp_config("map_value_source", r#"{"server": {"port": 8080}}"#);
+        let args = vec![
+            "cli".to_string(),
+            "--config".to_string(),
+            path.to_str().unwrap().to_string(),
+        ];
+        let context = Context::new(args, Some(vec![]));
+
+        assert!(matches!(
+            context.get_value("server"),
+            Some(ContextValue::Map(_))
+        ));
+        assert_eq!(Some(ValueSource::ConfigFile), context.value_source("server"));
+        std::fs::remove_file(path).ok();
+    }
+
+    #[cfg(feature = "config_json")]
+    #[test]
+    fn missing_config_file_surfaces_as_flag_error_rather_than_silent_fallback() {
+        let args = vec![
+            "cli".to_string(),
+            "--config".to_string(),
+            "/no/such/seahorse_test_config.json".to_string(),
+        ];
+        let flags = vec![Flag::new("string", "", FlagType::String)];
+        let context = Context::new(args, Some(flags));
+
+        assert!(context.string_flag("string").is_err());
+        assert_eq!(None, context.get_value("string"));
+    }
+
+    #[cfg(feature = "config_json")]
+    #[test]
+    fn malformed_config_file_surfaces_as_flag_error_rather_than_silent_fallback() {
+        let path = write_temp_config("malformed", "{ not json");
+        let args = vec![
+            "cli".to_string(),
+            "--config".to_string(),
+            path.to_str().unwrap().to_string(),
+        ];
+        let flags = vec![Flag::new("string", "", FlagType::String)];
+        let context = Context::new(args, Some(flags));
+
+        assert!(context.string_flag("string").is_err());
+        assert_eq!(None, context.get_value("string"));
+        std::fs::remove_file(path).ok();
     }
 }
@@ -0,0 +1,148 @@
+/// `FlagType` enum
+///
+/// This enum is used to determine the type of value owned by `Flag`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlagType {
+    Bool,
+    String,
+    Int,
+    Float,
+}
+
+/// `FlagValue` enum
+///
+/// This enum is used to represent the value parsed for a `Flag`
+#[derive(Debug, Clone, PartialEq)]
+pub enum FlagValue {
+    Bool(bool),
+    String(String),
+    Int(isize),
+    Float(f64),
+    /// Every occurrence of a `multiple` flag, in command-line order
+    List(Vec<FlagValue>),
+}
+
+/// `Flag` struct
+///
+/// This struct is used to define a command line flag: its name, its usage string (shown in help
+/// output), and the type of value it carries
+#[derive(Debug, Clone)]
+pub struct Flag {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub flag_type: FlagType,
+    /// Whether this flag may occur more than once on the command line, collecting every
+    /// occurrence into a `FlagValue::List`
+    pub multiple: bool,
+    /// An environment variable consulted when this flag has no command line occurrence
+    pub env: Option<&'static str>,
+}
+
+impl Flag {
+    /// Create new instance of `Flag`
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use seahorse::{Flag, FlagType};
+    ///
+    /// let flag = Flag::new("bool", "cli cmd [arg] --bool", FlagType::Bool);
+    /// ```
+    pub fn new(name: &'static str, usage: &'static str, flag_type: FlagType) -> Self {
+        Self {
+            name,
+            usage,
+            flag_type,
+            multiple: false,
+            env: None,
+        }
+    }
+
+    /// Mark this flag as repeatable: every occurrence on the command line is collected, in
+    /// order, into a `FlagValue::List`.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use seahorse::{Flag, FlagType};
+    ///
+    /// let flag = Flag::new("tag", "cli cmd [arg] --tag [tag]...", FlagType::String).multiple();
+    /// ```
+    pub fn multiple(mut self) -> Self {
+        self.multiple = true;
+        self
+    }
+
+    /// Associate an environment variable with this flag, consulted when it has no command
+    /// line occurrence.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use seahorse::{Flag, FlagType};
+    ///
+    /// let flag = Flag::new("token", "cli cmd [arg] --token [token]", FlagType::String)
+    ///     .env("MYCLI_TOKEN");
+    /// ```
+    pub fn env(mut self, name: &'static str) -> Self {
+        self.env = Some(name);
+        self
+    }
+
+    /// Get the index of this flag's first occurrence in `v`, if any
+    pub fn option_index(&self, v: &[String]) -> Option<usize> {
+        v.iter().position(|r| r == &format!("--{}", self.name))
+    }
+
+    /// Parse this flag's value out of the token following its occurrence (if any)
+    pub fn value(&self, v: Option<String>) -> Result<FlagValue, String> {
+        match self.flag_type {
+            FlagType::Bool => Ok(FlagValue::Bool(true)),
+            FlagType::String => match v {
+                Some(val) => Ok(FlagValue::String(val)),
+                None => Err("Flag needs a value".to_string()),
+            },
+            FlagType::Int => match v {
+                Some(val) => match val.parse::<isize>() {
+                    Ok(val) => Ok(FlagValue::Int(val)),
+                    Err(_) => Err(format!("{} cannot convert int", val)),
+                },
+                None => Err("Flag needs a value".to_string()),
+            },
+            FlagType::Float => match v {
+                Some(val) => match val.parse::<f64>() {
+                    Ok(val) => Ok(FlagValue::Float(val)),
+                    Err(_) => Err(format!("{} cannot convert float", val)),
+                },
+                None => Err("Flag needs a value".to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_index_test() {
+        let args = vec!["cli".to_string(), "--bool".to_string()];
+        let flag = Flag::new("bool", "", FlagType::Bool);
+        assert_eq!(Some(1), flag.option_index(&args));
+        assert_eq!(None, flag.option_index(&["cli".to_string()]));
+    }
+
+    #[test]
+    fn multiple_builder_test() {
+        let flag = Flag::new("tag", "", FlagType::String);
+        assert!(!flag.multiple);
+        assert!(flag.multiple().multiple);
+    }
+
+    #[test]
+    fn env_builder_test() {
+        let flag = Flag::new("token", "", FlagType::String);
+        assert_eq!(None, flag.env);
+        assert_eq!(Some("MYCLI_TOKEN"), flag.env("MYCLI_TOKEN").env);
+    }
+}
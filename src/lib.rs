@@ -0,0 +1,11 @@
+//! `seahorse` is a minimal CLI framework for Rust
+
+mod completions;
+mod context;
+mod flag;
+
+pub use completions::{generate_completions, Shell};
+#[cfg(any(feature = "config_toml", feature = "config_json"))]
+pub use context::ConfigMap;
+pub use context::{parse_string, Context, ContextValue, ValueKind, ValueSource};
+pub use flag::{Flag, FlagType, FlagValue};